@@ -4,10 +4,342 @@ use rand::Rng;
 pub trait Drawable {
     fn draw(&self, image: &mut Image);
     fn color(&self) -> Color;
+
+    /// Anti-aliased variant of `draw`. Shapes that support a smoother
+    /// rasterization path (currently `Line` and `Circle`) override this;
+    /// everything else just falls back to the regular outline.
+    fn draw_aa(&self, image: &mut Image) {
+        self.draw(image);
+    }
+
+    /// Renders the shape solid instead of as an outline. Shapes that have
+    /// an interior (currently `Triangle`, `Rectangle`, `Pentagon` and
+    /// `Circle`) override this; everything else falls back to `draw`.
+    fn fill(&self, image: &mut Image, _rule: FillRule) {
+        self.draw(image);
+    }
+
+    /// Outline variant of `draw` that composites through `mode` instead of
+    /// overwriting. Falls back to the opaque `draw` for shapes that don't
+    /// override it.
+    fn draw_blended(&self, image: &mut Image, _mode: BlendMode) {
+        self.draw(image);
+    }
+
+    /// Solid variant of `fill` that composites through `mode` instead of
+    /// overwriting, so stacked translucent shapes accumulate color instead
+    /// of the last one drawn winning. Falls back to the opaque `fill`.
+    fn fill_blended(&self, image: &mut Image, rule: FillRule, _mode: BlendMode) {
+        self.fill(image, rule);
+    }
+
+    /// Outline variant of `draw` that samples `paint` per pixel instead of
+    /// one flat random color. Falls back to the opaque `draw`.
+    fn draw_with_paint(&self, image: &mut Image, _paint: &Paint) {
+        self.draw(image);
+    }
+
+    /// Solid variant of `fill` that samples `paint` per pixel instead of
+    /// one flat random color, e.g. a pentagon filled with a linear
+    /// gradient. Falls back to the opaque `fill`.
+    fn fill_with_paint(&self, image: &mut Image, rule: FillRule, _paint: &Paint) {
+        self.fill(image, rule);
+    }
+}
+
+/// Which pixels count as "inside" a polygon when filling it, for polygons
+/// whose edges cross themselves (e.g. a star-shaped outline).
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum FillRule {
+    /// A point is inside if a ray to it crosses an odd number of edges.
+    EvenOdd,
+    /// A point is inside if the signed count of edges crossed (+1 going
+    /// down, -1 going up) is non-zero.
+    NonZero,
+}
+
+/// Generic scanline polygon fill. `points` is an ordered polygon outline
+/// (implicitly closed back to the first point). For each scanline, finds
+/// where the polygon edges cross it, sorts the crossings, then fills the
+/// spans between them according to `rule`.
+fn fill_polygon(image: &mut Image, points: &[Point], color: &Color, rule: FillRule) {
+    fill_polygon_impl(image, points, color, rule, None);
+}
+
+/// Like `fill_polygon`, but composites each plotted pixel through `mode`
+/// instead of overwriting.
+fn fill_polygon_blended(image: &mut Image, points: &[Point], color: &Color, rule: FillRule, mode: BlendMode) {
+    fill_polygon_impl(image, points, color, rule, Some(mode));
+}
+
+fn fill_polygon_impl(
+    image: &mut Image,
+    points: &[Point],
+    color: &Color,
+    rule: FillRule,
+    mode: Option<BlendMode>,
+) {
+    if points.len() < 3 {
+        return;
+    }
+
+    let plot = |image: &mut Image, x: i32, y: i32, color: &Color| match mode {
+        Some(mode) => image.blend_pixel_with(x, y, color.clone(), mode),
+        None => image.display(x, y, color.clone()),
+    };
+
+    let y_min = points.iter().map(|p| p.y).min().unwrap();
+    let y_max = points.iter().map(|p| p.y).max().unwrap();
+    let n = points.len();
+
+    for y in y_min..=y_max {
+        // Each crossing is (x, winding direction of the edge it came from).
+        let mut crossings: Vec<(f64, i32)> = Vec::new();
+        for i in 0..n {
+            let a = &points[i];
+            let b = &points[(i + 1) % n];
+            if a.y == b.y {
+                continue;
+            }
+            let (lo, hi, dir) = if a.y < b.y { (a, b, 1) } else { (b, a, -1) };
+            if y >= lo.y && y < hi.y {
+                let t = (y - lo.y) as f64 / (hi.y - lo.y) as f64;
+                crossings.push((lo.x as f64 + t * (hi.x - lo.x) as f64, dir));
+            }
+        }
+        crossings.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+
+        match rule {
+            FillRule::EvenOdd => {
+                let mut i = 0;
+                while i + 1 < crossings.len() {
+                    let xs = crossings[i].0.round() as i32;
+                    let xe = crossings[i + 1].0.round() as i32;
+                    for x in xs..=xe {
+                        plot(image, x, y, color);
+                    }
+                    i += 2;
+                }
+            }
+            FillRule::NonZero => {
+                let mut winding = 0;
+                for i in 0..crossings.len().saturating_sub(1) {
+                    winding += crossings[i].1;
+                    if winding != 0 {
+                        let xs = crossings[i].0.round() as i32;
+                        let xe = crossings[i + 1].0.round() as i32;
+                        for x in xs..=xe {
+                            plot(image, x, y, color);
+                        }
+                    }
+                }
+            }
+        }
+    }
 }
 
 pub trait Displayable {
     fn display(&mut self, x: i32, y: i32, color: Color);
+
+    /// Reads back the current color of a pixel, used for alpha compositing.
+    /// Out-of-bounds reads return fully transparent black.
+    fn get(&self, x: i32, y: i32) -> Color;
+
+    /// Composites `color` over the existing pixel using source-over:
+    /// `out = fg*a + bg*(255-a)`, computed per channel and divided by 255.
+    fn blend_pixel(&mut self, x: i32, y: i32, color: Color) {
+        self.blend_pixel_with(x, y, color, BlendMode::SrcOver);
+    }
+
+    /// Like `blend_pixel`, but first mixes `color` into the existing pixel
+    /// through `mode` (Porter-Duff `SrcOver`, or one of the separable blend
+    /// modes), then weights the mixed result over the background by alpha
+    /// using the same source-over compositing as `blend_pixel`.
+    fn blend_pixel_with(&mut self, x: i32, y: i32, color: Color, mode: BlendMode) {
+        let bg = self.get(x, y);
+        let a = color.a as u32;
+        let over = |fg: u8, bg: u8| -> u8 {
+            let mixed = blend_channel(mode, fg, bg) as u32;
+            ((mixed * a + bg as u32 * (255 - a)) / 255) as u8
+        };
+        self.display(
+            x,
+            y,
+            Color {
+                r: over(color.r, bg.r),
+                g: over(color.g, bg.g),
+                b: over(color.b, bg.b),
+                a: 255,
+            },
+        );
+    }
+}
+
+/// Porter-Duff `SrcOver` plus the separable blend modes: each mixes the
+/// source and destination channel values before the usual alpha-weighted
+/// source-over composite in `blend_pixel_with`.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum BlendMode {
+    SrcOver,
+    Multiply,
+    Screen,
+    Darken,
+    Lighten,
+    Difference,
+    Add,
+}
+
+fn blend_channel(mode: BlendMode, fg: u8, bg: u8) -> u8 {
+    match mode {
+        BlendMode::SrcOver => fg,
+        BlendMode::Multiply => ((fg as u32 * bg as u32) / 255) as u8,
+        BlendMode::Screen => (255 - ((255 - fg as u32) * (255 - bg as u32)) / 255) as u8,
+        BlendMode::Darken => fg.min(bg),
+        BlendMode::Lighten => fg.max(bg),
+        BlendMode::Difference => (fg as i32 - bg as i32).unsigned_abs() as u8,
+        BlendMode::Add => (fg as u32 + bg as u32).min(255) as u8,
+    }
+}
+
+/// A color source that can be sampled per pixel, instead of every shape
+/// only ever producing one flat random `Color`.
+pub enum Paint {
+    Solid(Color),
+    LinearGradient {
+        start: Point,
+        end: Point,
+        stops: Vec<(f32, Color)>,
+    },
+    RadialGradient {
+        center: Point,
+        radius: f64,
+        stops: Vec<(f32, Color)>,
+    },
+}
+
+impl Paint {
+    fn sample(&self, x: i32, y: i32) -> Color {
+        match self {
+            Paint::Solid(color) => color.clone(),
+            Paint::LinearGradient { start, end, stops } => {
+                let dx = (end.x - start.x) as f64;
+                let dy = (end.y - start.y) as f64;
+                let len_sq = dx * dx + dy * dy;
+                let t = if len_sq == 0.0 {
+                    0.0
+                } else {
+                    ((x - start.x) as f64 * dx + (y - start.y) as f64 * dy) / len_sq
+                };
+                sample_stops(stops, t.clamp(0.0, 1.0) as f32)
+            }
+            Paint::RadialGradient {
+                center,
+                radius,
+                stops,
+            } => {
+                let dx = (x - center.x) as f64;
+                let dy = (y - center.y) as f64;
+                let dist = (dx * dx + dy * dy).sqrt();
+                let t = if *radius == 0.0 { 0.0 } else { dist / radius };
+                sample_stops(stops, t.clamp(0.0, 1.0) as f32)
+            }
+        }
+    }
+}
+
+/// Sorts `stops` by position, clamps `t` to the outer stops, and linearly
+/// interpolates between whichever pair straddles it.
+fn sample_stops(stops: &[(f32, Color)], t: f32) -> Color {
+    let mut sorted: Vec<&(f32, Color)> = stops.iter().collect();
+    sorted.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+
+    match sorted.as_slice() {
+        [] => Color::rgba(0, 0, 0, 0),
+        [(_, color)] => color.clone(),
+        _ => {
+            if t <= sorted[0].0 {
+                return sorted[0].1.clone();
+            }
+            if t >= sorted[sorted.len() - 1].0 {
+                return sorted[sorted.len() - 1].1.clone();
+            }
+            for pair in sorted.windows(2) {
+                let (t0, c0) = &pair[0];
+                let (t1, c1) = &pair[1];
+                if t <= *t1 {
+                    let span = (t1 - t0).max(f32::EPSILON);
+                    return lerp_color(c0, c1, (t - t0) / span);
+                }
+            }
+            sorted[sorted.len() - 1].1.clone()
+        }
+    }
+}
+
+fn lerp_color(a: &Color, b: &Color, t: f32) -> Color {
+    let lerp = |a: u8, b: u8| (a as f32 + (b as f32 - a as f32) * t).round() as u8;
+    Color {
+        r: lerp(a.r, b.r),
+        g: lerp(a.g, b.g),
+        b: lerp(a.b, b.b),
+        a: lerp(a.a, b.a),
+    }
+}
+
+/// Like `fill_polygon`, but samples `paint` at every plotted pixel instead
+/// of using one flat color.
+fn fill_polygon_with_paint(image: &mut Image, points: &[Point], paint: &Paint, rule: FillRule) {
+    if points.len() < 3 {
+        return;
+    }
+
+    let y_min = points.iter().map(|p| p.y).min().unwrap();
+    let y_max = points.iter().map(|p| p.y).max().unwrap();
+    let n = points.len();
+
+    for y in y_min..=y_max {
+        let mut crossings: Vec<(f64, i32)> = Vec::new();
+        for i in 0..n {
+            let a = &points[i];
+            let b = &points[(i + 1) % n];
+            if a.y == b.y {
+                continue;
+            }
+            let (lo, hi, dir) = if a.y < b.y { (a, b, 1) } else { (b, a, -1) };
+            if y >= lo.y && y < hi.y {
+                let t = (y - lo.y) as f64 / (hi.y - lo.y) as f64;
+                crossings.push((lo.x as f64 + t * (hi.x - lo.x) as f64, dir));
+            }
+        }
+        crossings.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+
+        match rule {
+            FillRule::EvenOdd => {
+                let mut i = 0;
+                while i + 1 < crossings.len() {
+                    let xs = crossings[i].0.round() as i32;
+                    let xe = crossings[i + 1].0.round() as i32;
+                    for x in xs..=xe {
+                        image.display(x, y, paint.sample(x, y));
+                    }
+                    i += 2;
+                }
+            }
+            FillRule::NonZero => {
+                let mut winding = 0;
+                for i in 0..crossings.len().saturating_sub(1) {
+                    winding += crossings[i].1;
+                    if winding != 0 {
+                        let xs = crossings[i].0.round() as i32;
+                        let xe = crossings[i + 1].0.round() as i32;
+                        for x in xs..=xe {
+                            image.display(x, y, paint.sample(x, y));
+                        }
+                    }
+                }
+            }
+        }
+    }
 }
 
 // Point implementation
@@ -110,6 +442,152 @@ impl Drawable for Line {
             a: 255,
         }
     }
+
+    fn draw_aa(&self, image: &mut Image) {
+        let color = self.color();
+        draw_wu_line(image, &self.start, &self.end, &color);
+    }
+
+    fn draw_blended(&self, image: &mut Image, mode: BlendMode) {
+        let color = self.color();
+
+        // Bresenham's line algorithm, compositing through `mode` instead of overwriting
+        let mut x0 = self.start.x;
+        let mut y0 = self.start.y;
+        let x1 = self.end.x;
+        let y1 = self.end.y;
+
+        let dx = (x1 - x0).abs();
+        let sx = if x0 < x1 { 1 } else { -1 };
+        let dy = -(y1 - y0).abs();
+        let sy = if y0 < y1 { 1 } else { -1 };
+        let mut err = dx + dy;
+
+        loop {
+            image.blend_pixel_with(x0, y0, color.clone(), mode);
+            if x0 == x1 && y0 == y1 { break; }
+            let e2 = 2 * err;
+            if e2 >= dy {
+                if x0 == x1 { break; }
+                err += dy;
+                x0 += sx;
+            }
+            if e2 <= dx {
+                if y0 == y1 { break; }
+                err += dx;
+                y0 += sy;
+            }
+        }
+    }
+
+    fn draw_with_paint(&self, image: &mut Image, paint: &Paint) {
+        // Bresenham's line algorithm, sampling `paint` instead of one flat color
+        let mut x0 = self.start.x;
+        let mut y0 = self.start.y;
+        let x1 = self.end.x;
+        let y1 = self.end.y;
+
+        let dx = (x1 - x0).abs();
+        let sx = if x0 < x1 { 1 } else { -1 };
+        let dy = -(y1 - y0).abs();
+        let sy = if y0 < y1 { 1 } else { -1 };
+        let mut err = dx + dy;
+
+        loop {
+            image.display(x0, y0, paint.sample(x0, y0));
+            if x0 == x1 && y0 == y1 { break; }
+            let e2 = 2 * err;
+            if e2 >= dy {
+                if x0 == x1 { break; }
+                err += dy;
+                x0 += sx;
+            }
+            if e2 <= dx {
+                if y0 == y1 { break; }
+                err += dx;
+                y0 += sy;
+            }
+        }
+    }
+}
+
+/// Xiaolin Wu's line algorithm. Walks the major axis one pixel at a time
+/// and plots the two pixels straddling the true line, giving each a
+/// coverage (and therefore alpha) based on how close it is to the line.
+fn draw_wu_line(image: &mut Image, start: &Point, end: &Point, color: &Color) {
+    fn plot(image: &mut Image, x: i32, y: i32, coverage: f64, color: &Color) {
+        let a = (coverage.clamp(0.0, 1.0) * color.a as f64).round() as u8;
+        image.blend_pixel(
+            x,
+            y,
+            Color {
+                r: color.r,
+                g: color.g,
+                b: color.b,
+                a,
+            },
+        );
+    }
+
+    let (mut x0, mut y0) = (start.x as f64, start.y as f64);
+    let (mut x1, mut y1) = (end.x as f64, end.y as f64);
+
+    let steep = (y1 - y0).abs() > (x1 - x0).abs();
+    if steep {
+        std::mem::swap(&mut x0, &mut y0);
+        std::mem::swap(&mut x1, &mut y1);
+    }
+    if x0 > x1 {
+        std::mem::swap(&mut x0, &mut x1);
+        std::mem::swap(&mut y0, &mut y1);
+    }
+
+    let dx = x1 - x0;
+    let dy = y1 - y0;
+    let gradient = if dx == 0.0 { 1.0 } else { dy / dx };
+
+    // First endpoint.
+    let xend = x0.round();
+    let yend = y0 + gradient * (xend - x0);
+    let xgap = 1.0 - (x0 + 0.5).fract();
+    let xpxl1 = xend as i32;
+    let ypxl1 = yend.floor() as i32;
+    if steep {
+        plot(image, ypxl1, xpxl1, (1.0 - yend.fract()) * xgap, color);
+        plot(image, ypxl1 + 1, xpxl1, yend.fract() * xgap, color);
+    } else {
+        plot(image, xpxl1, ypxl1, (1.0 - yend.fract()) * xgap, color);
+        plot(image, xpxl1, ypxl1 + 1, yend.fract() * xgap, color);
+    }
+    let mut intery = yend + gradient;
+
+    // Second endpoint.
+    let xend = x1.round();
+    let yend = y1 + gradient * (xend - x1);
+    let xgap = (x1 + 0.5).fract();
+    let xpxl2 = xend as i32;
+    let ypxl2 = yend.floor() as i32;
+    if steep {
+        plot(image, ypxl2, xpxl2, (1.0 - yend.fract()) * xgap, color);
+        plot(image, ypxl2 + 1, xpxl2, yend.fract() * xgap, color);
+    } else {
+        plot(image, xpxl2, ypxl2, (1.0 - yend.fract()) * xgap, color);
+        plot(image, xpxl2, ypxl2 + 1, yend.fract() * xgap, color);
+    }
+
+    // Interior pixels along the major axis.
+    for x in (xpxl1 + 1)..xpxl2 {
+        let y = intery.floor() as i32;
+        let frac = intery.fract();
+        if steep {
+            plot(image, y, x, 1.0 - frac, color);
+            plot(image, y + 1, x, frac, color);
+        } else {
+            plot(image, x, y, 1.0 - frac, color);
+            plot(image, x, y + 1, frac, color);
+        }
+        intery += gradient;
+    }
 }
 
 // Pentagon implementation
@@ -135,12 +613,10 @@ impl Pentagon {
             radius: rng.gen_range(5..max_radius),
         }
     }
-}
 
-impl Drawable for Pentagon {
-    fn draw(&self, image: &mut Image) {
-        // Calculate five points on a circle to form a pentagon
-        let points: Vec<Point> = (0..5)
+    // Calculate five points on a circle to form a pentagon
+    pub fn points(&self) -> Vec<Point> {
+        (0..5)
             .map(|i| {
                 let angle = 2.0 * std::f64::consts::PI * (i as f64) / 5.0;
                 Point::new(
@@ -148,8 +624,14 @@ impl Drawable for Pentagon {
                     self.center.y + (self.radius as f64 * angle.sin()) as i32,
                 )
             })
-            .collect();
-        
+            .collect()
+    }
+}
+
+impl Drawable for Pentagon {
+    fn draw(&self, image: &mut Image) {
+        let points = self.points();
+
         // Draw the pentagon by connecting the points
         for i in 0..5 {
             let next = (i + 1) % 5;
@@ -166,77 +648,243 @@ impl Drawable for Pentagon {
             a: 255,
         }
     }
+
+    fn fill(&self, image: &mut Image, rule: FillRule) {
+        let color = self.color();
+        fill_polygon(image, &self.points(), &color, rule);
+    }
+
+    fn draw_blended(&self, image: &mut Image, mode: BlendMode) {
+        let points = self.points();
+        for i in 0..5 {
+            let next = (i + 1) % 5;
+            Line::new(&points[i], &points[next]).draw_blended(image, mode);
+        }
+    }
+
+    fn fill_blended(&self, image: &mut Image, rule: FillRule, mode: BlendMode) {
+        let color = self.color();
+        fill_polygon_blended(image, &self.points(), &color, rule, mode);
+    }
+
+    fn draw_with_paint(&self, image: &mut Image, paint: &Paint) {
+        let points = self.points();
+        for i in 0..5 {
+            let next = (i + 1) % 5;
+            Line::new(&points[i], &points[next]).draw_with_paint(image, paint);
+        }
+    }
+
+    fn fill_with_paint(&self, image: &mut Image, rule: FillRule, paint: &Paint) {
+        fill_polygon_with_paint(image, &self.points(), paint, rule);
+    }
+}
+
+// Mesh implementation: a plain 3D vertex/edge/triangle buffer shared by any
+// polyhedron that needs rotation, projection or STL export (currently just
+// `Cube`, but nothing here is cube-specific).
+pub struct Mesh {
+    vertices: Vec<(f64, f64, f64)>,
+    edges: Vec<(usize, usize)>,
+    triangles: Vec<(usize, usize, usize)>,
+}
+
+/// How a rotated 3D point is flattened to 2D screen space.
+#[derive(Clone, Copy)]
+pub enum Projection {
+    /// Drops z entirely.
+    Orthographic,
+    /// `x' = x*focal/(focal+z)`, same for y.
+    Perspective { focal_length: f64 },
+}
+
+fn project(x: f64, y: f64, z: f64, projection: Projection) -> (f64, f64) {
+    match projection {
+        Projection::Orthographic => (x, y),
+        Projection::Perspective { focal_length } => {
+            let scale = focal_length / (focal_length + z);
+            (x * scale, y * scale)
+        }
+    }
+}
+
+/// Rotates `(x, y, z)` by `roll` around Z, then `pitch` around X, then `yaw`
+/// around Y (all in radians), using the standard rotation matrices.
+fn rotate_point(x: f64, y: f64, z: f64, yaw: f64, pitch: f64, roll: f64) -> (f64, f64, f64) {
+    let (sz, cz) = roll.sin_cos();
+    let (x1, y1, z1) = (x * cz - y * sz, x * sz + y * cz, z);
+
+    let (sx, cx) = pitch.sin_cos();
+    let (x2, y2, z2) = (x1, y1 * cx - z1 * sx, y1 * sx + z1 * cx);
+
+    let (sy, cy) = yaw.sin_cos();
+    (x2 * cy + z2 * sy, y2, z2 * cy - x2 * sy)
+}
+
+impl Mesh {
+    pub fn new(
+        vertices: Vec<(f64, f64, f64)>,
+        edges: Vec<(usize, usize)>,
+        triangles: Vec<(usize, usize, usize)>,
+    ) -> Self {
+        Mesh {
+            vertices,
+            edges,
+            triangles,
+        }
+    }
+
+    fn screen_points(&self, yaw: f64, pitch: f64, roll: f64, projection: Projection) -> Vec<(f64, f64)> {
+        self.vertices
+            .iter()
+            .map(|&(x, y, z)| {
+                let (x, y, z) = rotate_point(x, y, z, yaw, pitch, roll);
+                project(x, y, z, projection)
+            })
+            .collect()
+    }
+
+    /// Serializes the triangle list as binary STL: an 80-byte zero header,
+    /// a `u32` triangle count, then per triangle a zero normal, three
+    /// little-endian `f32` vertex triples, and a zero attribute byte count.
+    pub fn to_stl(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(84 + self.triangles.len() * 50);
+        bytes.extend_from_slice(&[0u8; 80]);
+        bytes.extend_from_slice(&(self.triangles.len() as u32).to_le_bytes());
+
+        for &(a, b, c) in &self.triangles {
+            bytes.extend_from_slice(&0f32.to_le_bytes()); // normal.x
+            bytes.extend_from_slice(&0f32.to_le_bytes()); // normal.y
+            bytes.extend_from_slice(&0f32.to_le_bytes()); // normal.z
+            for &index in &[a, b, c] {
+                let (x, y, z) = self.vertices[index];
+                bytes.extend_from_slice(&(x as f32).to_le_bytes());
+                bytes.extend_from_slice(&(y as f32).to_le_bytes());
+                bytes.extend_from_slice(&(z as f32).to_le_bytes());
+            }
+            bytes.extend_from_slice(&0u16.to_le_bytes()); // attribute byte count
+        }
+
+        bytes
+    }
+
+    pub fn save_stl(&self, path: &str) -> std::io::Result<()> {
+        std::fs::write(path, self.to_stl())
+    }
 }
 
 // Cube implementation
 pub struct Cube {
-    front_top_left: Point,
-    size: i32,
-    depth_factor: f64, // For perspective effect (0.0-1.0)
+    center: Point,
+    yaw: f64,
+    pitch: f64,
+    roll: f64,
+    projection: Projection,
+    mesh: Mesh,
 }
 
 impl Cube {
-    pub fn new(front_top_left: &Point, size: i32) -> Self {
-        Cube { 
-            front_top_left: Point::new(front_top_left.x, front_top_left.y), 
-            size,
-            depth_factor: 0.5,
+    /// `center` anchors the cube in screen space, `size` is its edge
+    /// length, and `yaw`/`pitch`/`roll` (radians) rotate it around the Y,
+    /// X and Z axes before it's projected to 2D.
+    pub fn new(center: &Point, size: i32, yaw: f64, pitch: f64, roll: f64) -> Self {
+        let h = size as f64 / 2.0;
+        let vertices = vec![
+            (-h, -h, -h),
+            (h, -h, -h),
+            (h, h, -h),
+            (-h, h, -h),
+            (-h, -h, h),
+            (h, -h, h),
+            (h, h, h),
+            (-h, h, h),
+        ];
+        let edges = vec![
+            (0, 1),
+            (1, 2),
+            (2, 3),
+            (3, 0),
+            (4, 5),
+            (5, 6),
+            (6, 7),
+            (7, 4),
+            (0, 4),
+            (1, 5),
+            (2, 6),
+            (3, 7),
+        ];
+        let triangles = vec![
+            (0, 1, 2),
+            (0, 2, 3), // back
+            (4, 6, 5),
+            (4, 7, 6), // front
+            (0, 5, 1),
+            (0, 4, 5), // bottom
+            (3, 2, 6),
+            (3, 6, 7), // top
+            (0, 3, 7),
+            (0, 7, 4), // left
+            (1, 6, 2),
+            (1, 5, 6), // right
+        ];
+
+        Cube {
+            center: Point::new(center.x, center.y),
+            yaw,
+            pitch,
+            roll,
+            projection: Projection::Orthographic,
+            mesh: Mesh::new(vertices, edges, triangles),
         }
     }
-    
+
+    /// Switches from the default orthographic projection to a perspective
+    /// one with the given focal length.
+    pub fn with_perspective(mut self, focal_length: f64) -> Self {
+        self.projection = Projection::Perspective { focal_length };
+        self
+    }
+
     pub fn random(width: i32, height: i32) -> Self {
         let mut rng = rand::thread_rng();
         let max_size = (width.min(height) / 8).max(10);
         let size = rng.gen_range(10..max_size);
-        
+
         // Make sure the cube fits within the image
         let x = rng.gen_range(size..(width - size));
         let y = rng.gen_range(size..(height - size));
-        
-        Cube {
-            front_top_left: Point::new(x, y),
+
+        let tau = std::f64::consts::TAU;
+        Cube::new(
+            &Point::new(x, y),
             size,
-            depth_factor: rng.gen_range(0.3..0.7),
-        }
+            rng.gen_range(0.0..tau),
+            rng.gen_range(0.0..tau),
+            rng.gen_range(0.0..tau),
+        )
+    }
+
+    /// Writes the cube's geometry out as binary STL, independent of
+    /// whatever it looks like once rendered into the image.
+    pub fn save_stl(&self, path: &str) -> std::io::Result<()> {
+        self.mesh.save_stl(path)
+    }
+
+    fn screen_points(&self) -> Vec<Point> {
+        self.mesh
+            .screen_points(self.yaw, self.pitch, self.roll, self.projection)
+            .iter()
+            .map(|&(x, y)| Point::new(self.center.x + x.round() as i32, self.center.y + y.round() as i32))
+            .collect()
     }
 }
 
 impl Drawable for Cube {
     fn draw(&self, image: &mut Image) {
-        // let color = self.color();
-        
-        // Calculate depth offset based on size and depth_factor
-        let depth = (self.size as f64 * self.depth_factor) as i32;
-        
-        // Front face points
-        let ftl = &self.front_top_left; // front top left
-        let ftr = &Point::new(ftl.x + self.size, ftl.y); // front top right
-        let fbl = &Point::new(ftl.x, ftl.y + self.size); // front bottom left
-        let fbr = &Point::new(ftl.x + self.size, ftl.y + self.size); // front bottom right
-        
-        // Back face points
-        let btl = &Point::new(ftl.x + depth, ftl.y - depth); // back top left
-        let btr = &Point::new(ftr.x + depth, ftr.y - depth); // back top right
-        let bbl = &Point::new(fbl.x + depth, fbl.y - depth); // back bottom left
-        let bbr = &Point::new(fbr.x + depth, fbr.y - depth); // back bottom right
-        
-        // Draw front face
-        Line::new(ftl, ftr).draw(image);
-        Line::new(ftr, fbr).draw(image);
-        Line::new(fbr, fbl).draw(image);
-        Line::new(fbl, ftl).draw(image);
-        
-        // Draw back face
-        Line::new(btl, btr).draw(image);
-        Line::new(btr, bbr).draw(image);
-        Line::new(bbr, bbl).draw(image);
-        Line::new(bbl, btl).draw(image);
-        
-        // Connect front and back faces
-        Line::new(ftl, btl).draw(image);
-        Line::new(ftr, btr).draw(image);
-        Line::new(fbl, bbl).draw(image);
-        Line::new(fbr, bbr).draw(image);
+        let points = self.screen_points();
+        for &(a, b) in &self.mesh.edges {
+            Line::new(&points[a], &points[b]).draw(image);
+        }
     }
 
     fn color(&self) -> Color {
@@ -287,6 +935,47 @@ impl Drawable for Triangle {
             a: 255,
         }
     }
+
+    fn fill(&self, image: &mut Image, rule: FillRule) {
+        let color = self.color();
+        let points = [
+            Point::new(self.a.x, self.a.y),
+            Point::new(self.b.x, self.b.y),
+            Point::new(self.c.x, self.c.y),
+        ];
+        fill_polygon(image, &points, &color, rule);
+    }
+
+    fn draw_blended(&self, image: &mut Image, mode: BlendMode) {
+        Line::new(&self.a, &self.b).draw_blended(image, mode);
+        Line::new(&self.b, &self.c).draw_blended(image, mode);
+        Line::new(&self.c, &self.a).draw_blended(image, mode);
+    }
+
+    fn fill_blended(&self, image: &mut Image, rule: FillRule, mode: BlendMode) {
+        let color = self.color();
+        let points = [
+            Point::new(self.a.x, self.a.y),
+            Point::new(self.b.x, self.b.y),
+            Point::new(self.c.x, self.c.y),
+        ];
+        fill_polygon_blended(image, &points, &color, rule, mode);
+    }
+
+    fn draw_with_paint(&self, image: &mut Image, paint: &Paint) {
+        Line::new(&self.a, &self.b).draw_with_paint(image, paint);
+        Line::new(&self.b, &self.c).draw_with_paint(image, paint);
+        Line::new(&self.c, &self.a).draw_with_paint(image, paint);
+    }
+
+    fn fill_with_paint(&self, image: &mut Image, rule: FillRule, paint: &Paint) {
+        let points = [
+            Point::new(self.a.x, self.a.y),
+            Point::new(self.b.x, self.b.y),
+            Point::new(self.c.x, self.c.y),
+        ];
+        fill_polygon_with_paint(image, &points, paint, rule);
+    }
 }
 
 // Rectangle implementation
@@ -324,6 +1013,64 @@ impl Drawable for Rectangle {
             a: 255,
         }
     }
+
+    fn fill(&self, image: &mut Image, rule: FillRule) {
+        let color = self.color();
+        let top_right = Point::new(self.bottom_right.x, self.top_left.y);
+        let bottom_left = Point::new(self.top_left.x, self.bottom_right.y);
+        let points = [
+            Point::new(self.top_left.x, self.top_left.y),
+            top_right,
+            Point::new(self.bottom_right.x, self.bottom_right.y),
+            bottom_left,
+        ];
+        fill_polygon(image, &points, &color, rule);
+    }
+
+    fn draw_blended(&self, image: &mut Image, mode: BlendMode) {
+        let top_right = Point::new(self.bottom_right.x, self.top_left.y);
+        let bottom_left = Point::new(self.top_left.x, self.bottom_right.y);
+
+        Line::new(&self.top_left, &top_right).draw_blended(image, mode);
+        Line::new(&top_right, &self.bottom_right).draw_blended(image, mode);
+        Line::new(&self.bottom_right, &bottom_left).draw_blended(image, mode);
+        Line::new(&bottom_left, &self.top_left).draw_blended(image, mode);
+    }
+
+    fn fill_blended(&self, image: &mut Image, rule: FillRule, mode: BlendMode) {
+        let color = self.color();
+        let top_right = Point::new(self.bottom_right.x, self.top_left.y);
+        let bottom_left = Point::new(self.top_left.x, self.bottom_right.y);
+        let points = [
+            Point::new(self.top_left.x, self.top_left.y),
+            top_right,
+            Point::new(self.bottom_right.x, self.bottom_right.y),
+            bottom_left,
+        ];
+        fill_polygon_blended(image, &points, &color, rule, mode);
+    }
+
+    fn draw_with_paint(&self, image: &mut Image, paint: &Paint) {
+        let top_right = Point::new(self.bottom_right.x, self.top_left.y);
+        let bottom_left = Point::new(self.top_left.x, self.bottom_right.y);
+
+        Line::new(&self.top_left, &top_right).draw_with_paint(image, paint);
+        Line::new(&top_right, &self.bottom_right).draw_with_paint(image, paint);
+        Line::new(&self.bottom_right, &bottom_left).draw_with_paint(image, paint);
+        Line::new(&bottom_left, &self.top_left).draw_with_paint(image, paint);
+    }
+
+    fn fill_with_paint(&self, image: &mut Image, rule: FillRule, paint: &Paint) {
+        let top_right = Point::new(self.bottom_right.x, self.top_left.y);
+        let bottom_left = Point::new(self.top_left.x, self.bottom_right.y);
+        let points = [
+            Point::new(self.top_left.x, self.top_left.y),
+            top_right,
+            Point::new(self.bottom_right.x, self.bottom_right.y),
+            bottom_left,
+        ];
+        fill_polygon_with_paint(image, &points, paint, rule);
+    }
 }
 
 // Circle implementation
@@ -333,7 +1080,6 @@ pub struct Circle {
 }
 
 impl Circle {
-    #[allow(dead_code)]
     pub fn new(center: &Point, radius: i32) -> Self {
         Circle { 
             center: Point::new(center.x, center.y), 
@@ -389,4 +1135,405 @@ impl Drawable for Circle {
             a: 255,
         }
     }
-}
\ No newline at end of file
+
+    fn draw_aa(&self, image: &mut Image) {
+        let color = self.color();
+        draw_wu_circle(image, &self.center, self.radius, &color);
+    }
+
+    fn fill(&self, image: &mut Image, _rule: FillRule) {
+        // A circle is convex, so even-odd and non-zero agree: fill each row
+        // between its horizontal extents x = center.x ± sqrt(r^2 - dy^2).
+        let color = self.color();
+        for dy in -self.radius..=self.radius {
+            let span = ((self.radius * self.radius - dy * dy) as f64).sqrt() as i32;
+            for dx in -span..=span {
+                image.display(self.center.x + dx, self.center.y + dy, color.clone());
+            }
+        }
+    }
+
+    fn draw_blended(&self, image: &mut Image, mode: BlendMode) {
+        let color = self.color();
+        let mut x = self.radius;
+        let mut y = 0;
+        let mut err = 0;
+
+        while x >= y {
+            image.blend_pixel_with(self.center.x + x, self.center.y + y, color.clone(), mode);
+            image.blend_pixel_with(self.center.x + y, self.center.y + x, color.clone(), mode);
+            image.blend_pixel_with(self.center.x - y, self.center.y + x, color.clone(), mode);
+            image.blend_pixel_with(self.center.x - x, self.center.y + y, color.clone(), mode);
+            image.blend_pixel_with(self.center.x - x, self.center.y - y, color.clone(), mode);
+            image.blend_pixel_with(self.center.x - y, self.center.y - x, color.clone(), mode);
+            image.blend_pixel_with(self.center.x + y, self.center.y - x, color.clone(), mode);
+            image.blend_pixel_with(self.center.x + x, self.center.y - y, color.clone(), mode);
+
+            y += 1;
+            if err <= 0 {
+                err += 2 * y + 1;
+            }
+            if err > 0 {
+                x -= 1;
+                err -= 2 * x + 1;
+            }
+        }
+    }
+
+    fn fill_blended(&self, image: &mut Image, _rule: FillRule, mode: BlendMode) {
+        let color = self.color();
+        for dy in -self.radius..=self.radius {
+            let span = ((self.radius * self.radius - dy * dy) as f64).sqrt() as i32;
+            for dx in -span..=span {
+                image.blend_pixel_with(self.center.x + dx, self.center.y + dy, color.clone(), mode);
+            }
+        }
+    }
+
+    fn draw_with_paint(&self, image: &mut Image, paint: &Paint) {
+        let mut x = self.radius;
+        let mut y = 0;
+        let mut err = 0;
+
+        while x >= y {
+            for &(px, py) in &[
+                (self.center.x + x, self.center.y + y),
+                (self.center.x + y, self.center.y + x),
+                (self.center.x - y, self.center.y + x),
+                (self.center.x - x, self.center.y + y),
+                (self.center.x - x, self.center.y - y),
+                (self.center.x - y, self.center.y - x),
+                (self.center.x + y, self.center.y - x),
+                (self.center.x + x, self.center.y - y),
+            ] {
+                image.display(px, py, paint.sample(px, py));
+            }
+
+            y += 1;
+            if err <= 0 {
+                err += 2 * y + 1;
+            }
+            if err > 0 {
+                x -= 1;
+                err -= 2 * x + 1;
+            }
+        }
+    }
+
+    fn fill_with_paint(&self, image: &mut Image, _rule: FillRule, paint: &Paint) {
+        for dy in -self.radius..=self.radius {
+            let span = ((self.radius * self.radius - dy * dy) as f64).sqrt() as i32;
+            for dx in -span..=span {
+                let x = self.center.x + dx;
+                let y = self.center.y + dy;
+                image.display(x, y, paint.sample(x, y));
+            }
+        }
+    }
+}
+
+/// Anti-aliased circle, using the same coverage-from-error-term idea as
+/// `draw_wu_line`: for each column walk out from the center, take the exact
+/// radial intersection `y = sqrt(r^2 - x^2)` and split its coverage between
+/// the two pixels straddling it.
+fn draw_wu_circle(image: &mut Image, center: &Point, radius: i32, color: &Color) {
+    fn plot(image: &mut Image, center: &Point, x: i32, y: i32, coverage: f64, color: &Color) {
+        let a = (coverage.clamp(0.0, 1.0) * color.a as f64).round() as u8;
+        let c = Color {
+            r: color.r,
+            g: color.g,
+            b: color.b,
+            a,
+        };
+        for &(sx, sy) in &[
+            (x, y),
+            (-x, y),
+            (x, -y),
+            (-x, -y),
+            (y, x),
+            (-y, x),
+            (y, -x),
+            (-y, -x),
+        ] {
+            image.blend_pixel(center.x + sx, center.y + sy, c.clone());
+        }
+    }
+
+    let r = radius as f64;
+    let limit = (r * std::f64::consts::FRAC_1_SQRT_2).floor() as i32;
+    for x in 0..=limit {
+        let exact_y = (r * r - (x * x) as f64).sqrt();
+        let y0 = exact_y.floor();
+        let frac = exact_y - y0;
+        plot(image, center, x, y0 as i32, 1.0 - frac, color);
+        plot(image, center, x, y0 as i32 + 1, frac, color);
+    }
+}
+/// How finely Bezier curves are flattened into line segments: a subdivided
+/// segment is accepted once its control points sit within this many pixels
+/// of the chord they'd be replaced by.
+const BEZIER_FLATNESS: f64 = 0.5;
+/// Safety net so a degenerate curve (zero-length chord, huge control point)
+/// can't recurse forever.
+const BEZIER_MAX_DEPTH: u32 = 16;
+
+fn midpoint(a: (f64, f64), b: (f64, f64)) -> (f64, f64) {
+    ((a.0 + b.0) / 2.0, (a.1 + b.1) / 2.0)
+}
+
+fn to_point(p: (f64, f64)) -> Point {
+    Point::new(p.0.round() as i32, p.1.round() as i32)
+}
+
+// Quadratic Bezier implementation
+pub struct QuadraticBezier {
+    p0: Point,
+    p1: Point,
+    p2: Point,
+}
+
+impl QuadraticBezier {
+    pub fn new(p0: &Point, p1: &Point, p2: &Point) -> Self {
+        QuadraticBezier {
+            p0: Point::new(p0.x, p0.y),
+            p1: Point::new(p1.x, p1.y),
+            p2: Point::new(p2.x, p2.y),
+        }
+    }
+
+    /// Flattens the curve into a polyline via de Casteljau subdivision:
+    /// recursively split the control polygon at t=0.5 until it is within
+    /// `BEZIER_FLATNESS` of the chord, then emit the resulting points.
+    fn flatten(&self) -> Vec<Point> {
+        let mut points = vec![Point::new(self.p0.x, self.p0.y)];
+        subdivide_quadratic(
+            (self.p0.x as f64, self.p0.y as f64),
+            (self.p1.x as f64, self.p1.y as f64),
+            (self.p2.x as f64, self.p2.y as f64),
+            0,
+            &mut points,
+        );
+        points
+    }
+}
+
+fn is_flat_quadratic(p0: (f64, f64), p1: (f64, f64), p2: (f64, f64)) -> bool {
+    let dx = p2.0 - p0.0;
+    let dy = p2.1 - p0.1;
+    let len = (dx * dx + dy * dy).sqrt();
+    if len == 0.0 {
+        return ((p1.0 - p0.0).powi(2) + (p1.1 - p0.1).powi(2)).sqrt() <= BEZIER_FLATNESS;
+    }
+    let dist = ((p1.0 - p0.0) * dy - (p1.1 - p0.1) * dx).abs() / len;
+    dist <= BEZIER_FLATNESS
+}
+
+fn subdivide_quadratic(
+    p0: (f64, f64),
+    p1: (f64, f64),
+    p2: (f64, f64),
+    depth: u32,
+    out: &mut Vec<Point>,
+) {
+    if depth >= BEZIER_MAX_DEPTH || is_flat_quadratic(p0, p1, p2) {
+        out.push(to_point(p2));
+        return;
+    }
+    let p01 = midpoint(p0, p1);
+    let p12 = midpoint(p1, p2);
+    let p012 = midpoint(p01, p12);
+    subdivide_quadratic(p0, p01, p012, depth + 1, out);
+    subdivide_quadratic(p012, p12, p2, depth + 1, out);
+}
+
+impl Drawable for QuadraticBezier {
+    fn draw(&self, image: &mut Image) {
+        let points = self.flatten();
+        for pair in points.windows(2) {
+            Line::new(&pair[0], &pair[1]).draw(image);
+        }
+    }
+
+    fn color(&self) -> Color {
+        let mut rng = rand::thread_rng();
+        Color {
+            r: rng.gen_range(0..255),
+            g: rng.gen_range(0..255),
+            b: rng.gen_range(0..255),
+            a: 255,
+        }
+    }
+}
+
+// Cubic Bezier implementation
+pub struct CubicBezier {
+    p0: Point,
+    p1: Point,
+    p2: Point,
+    p3: Point,
+}
+
+impl CubicBezier {
+    pub fn new(p0: &Point, p1: &Point, p2: &Point, p3: &Point) -> Self {
+        CubicBezier {
+            p0: Point::new(p0.x, p0.y),
+            p1: Point::new(p1.x, p1.y),
+            p2: Point::new(p2.x, p2.y),
+            p3: Point::new(p3.x, p3.y),
+        }
+    }
+
+    fn flatten(&self) -> Vec<Point> {
+        let mut points = vec![Point::new(self.p0.x, self.p0.y)];
+        subdivide_cubic(
+            (self.p0.x as f64, self.p0.y as f64),
+            (self.p1.x as f64, self.p1.y as f64),
+            (self.p2.x as f64, self.p2.y as f64),
+            (self.p3.x as f64, self.p3.y as f64),
+            0,
+            &mut points,
+        );
+        points
+    }
+}
+
+fn is_flat_cubic(p0: (f64, f64), p1: (f64, f64), p2: (f64, f64), p3: (f64, f64)) -> bool {
+    let dx = p3.0 - p0.0;
+    let dy = p3.1 - p0.1;
+    let len = (dx * dx + dy * dy).sqrt();
+    if len == 0.0 {
+        let d1 = ((p1.0 - p0.0).powi(2) + (p1.1 - p0.1).powi(2)).sqrt();
+        let d2 = ((p2.0 - p0.0).powi(2) + (p2.1 - p0.1).powi(2)).sqrt();
+        return d1 <= BEZIER_FLATNESS && d2 <= BEZIER_FLATNESS;
+    }
+    let d1 = ((p1.0 - p0.0) * dy - (p1.1 - p0.1) * dx).abs() / len;
+    let d2 = ((p2.0 - p0.0) * dy - (p2.1 - p0.1) * dx).abs() / len;
+    d1 <= BEZIER_FLATNESS && d2 <= BEZIER_FLATNESS
+}
+
+fn subdivide_cubic(
+    p0: (f64, f64),
+    p1: (f64, f64),
+    p2: (f64, f64),
+    p3: (f64, f64),
+    depth: u32,
+    out: &mut Vec<Point>,
+) {
+    if depth >= BEZIER_MAX_DEPTH || is_flat_cubic(p0, p1, p2, p3) {
+        out.push(to_point(p3));
+        return;
+    }
+    let p01 = midpoint(p0, p1);
+    let p12 = midpoint(p1, p2);
+    let p23 = midpoint(p2, p3);
+    let p012 = midpoint(p01, p12);
+    let p123 = midpoint(p12, p23);
+    let p0123 = midpoint(p012, p123);
+    subdivide_cubic(p0, p01, p012, p0123, depth + 1, out);
+    subdivide_cubic(p0123, p123, p23, p3, depth + 1, out);
+}
+
+impl Drawable for CubicBezier {
+    fn draw(&self, image: &mut Image) {
+        let points = self.flatten();
+        for pair in points.windows(2) {
+            Line::new(&pair[0], &pair[1]).draw(image);
+        }
+    }
+
+    fn color(&self) -> Color {
+        let mut rng = rand::thread_rng();
+        Color {
+            r: rng.gen_range(0..255),
+            g: rng.gen_range(0..255),
+            b: rng.gen_range(0..255),
+            a: 255,
+        }
+    }
+}
+
+// Chaikin corner-cutting implementation
+pub struct Chaikin {
+    points: Vec<Point>,
+    iterations: u32,
+    closed: bool,
+}
+
+impl Chaikin {
+    /// `points` is the control polyline, `iterations` how many rounds of
+    /// corner-cutting to apply, and `closed` whether the last point wraps
+    /// back around to the first.
+    pub fn new(points: Vec<Point>, iterations: u32, closed: bool) -> Self {
+        Chaikin {
+            points,
+            iterations,
+            closed,
+        }
+    }
+
+    fn smoothed(&self) -> Vec<Point> {
+        let mut current: Vec<Point> = self.points.iter().map(|p| Point::new(p.x, p.y)).collect();
+        for _ in 0..self.iterations {
+            current = chaikin_step(&current, self.closed);
+        }
+        current
+    }
+}
+
+/// Replaces each edge (P,Q) with two points at 1/4 and 3/4 along it
+/// (`0.75*P+0.25*Q` and `0.25*P+0.75*Q`). Open polylines keep their original
+/// endpoints so the curve doesn't pull away from where it starts and ends.
+fn chaikin_step(points: &[Point], closed: bool) -> Vec<Point> {
+    if points.len() < 2 {
+        return points.iter().map(|p| Point::new(p.x, p.y)).collect();
+    }
+
+    let edge_count = if closed { points.len() } else { points.len() - 1 };
+    let mut out = Vec::with_capacity(edge_count * 2);
+    for i in 0..edge_count {
+        let p = &points[i];
+        let q = &points[(i + 1) % points.len()];
+        out.push(Point::new(
+            (0.75 * p.x as f64 + 0.25 * q.x as f64).round() as i32,
+            (0.75 * p.y as f64 + 0.25 * q.y as f64).round() as i32,
+        ));
+        out.push(Point::new(
+            (0.25 * p.x as f64 + 0.75 * q.x as f64).round() as i32,
+            (0.25 * p.y as f64 + 0.75 * q.y as f64).round() as i32,
+        ));
+    }
+
+    if !closed {
+        let first = &points[0];
+        let last = &points[points.len() - 1];
+        out.insert(0, Point::new(first.x, first.y));
+        out.push(Point::new(last.x, last.y));
+    }
+
+    out
+}
+
+impl Drawable for Chaikin {
+    fn draw(&self, image: &mut Image) {
+        let points = self.smoothed();
+        let n = points.len();
+        if n < 2 {
+            return;
+        }
+        let edges = if self.closed { n } else { n - 1 };
+        for i in 0..edges {
+            let next = (i + 1) % n;
+            Line::new(&points[i], &points[next]).draw(image);
+        }
+    }
+
+    fn color(&self) -> Color {
+        let mut rng = rand::thread_rng();
+        Color {
+            r: rng.gen_range(0..255),
+            g: rng.gen_range(0..255),
+            b: rng.gen_range(0..255),
+            a: 255,
+        }
+    }
+}
@@ -38,12 +38,105 @@ fn main() {
 
     // Add cube
     let cube_point = gs::Point::new(700, 200);
-    let cube = gs::Cube::new(&cube_point, 120);
+    let cube = gs::Cube::new(&cube_point, 120, 0.6, 0.4, 0.0);
     cube.draw(&mut image);
+    cube.save_stl("cube.stl").unwrap();
+
+    // Add a second fixed cube, projected with perspective instead of orthographic
+    let perspective_point = gs::Point::new(400, 750);
+    gs::Cube::new(&perspective_point, 150, 0.3, 0.8, 0.2)
+        .with_perspective(400.0)
+        .draw(&mut image);
 
     // Add a random cube
     gs::Cube::random(image.width, image.height).draw(&mut image);
 
+    // Anti-aliased line and circle, drawn with Xiaolin Wu's algorithm
+    let aa_start = gs::Point::new(50, 900);
+    let aa_end = gs::Point::new(950, 950);
+    gs::Line::new(&aa_start, &aa_end).draw_aa(&mut image);
+
+    let aa_center = gs::Point::new(850, 150);
+    gs::Circle::new(&aa_center, 80).draw_aa(&mut image);
+
+    // Filled shapes via the scanline rasterizer
+    let filled_rect = gs::Rectangle::new(&gs::Point::new(20, 20), &gs::Point::new(120, 90));
+    filled_rect.fill(&mut image, gs::FillRule::NonZero);
+
+    let filled_pentagon = gs::Pentagon::new(&gs::Point::new(150, 950), 80);
+    filled_pentagon.fill(&mut image, gs::FillRule::EvenOdd);
+
+    let filled_circle = gs::Circle::new(&gs::Point::new(900, 450), 60);
+    filled_circle.fill(&mut image, gs::FillRule::NonZero);
+
+    // Quadratic and cubic Bezier curves, flattened via de Casteljau subdivision
+    let quadratic_bezier = gs::QuadraticBezier::new(
+        &gs::Point::new(50, 500),
+        &gs::Point::new(300, 150),
+        &gs::Point::new(550, 500),
+    );
+    quadratic_bezier.draw(&mut image);
+
+    let cubic_bezier = gs::CubicBezier::new(
+        &gs::Point::new(600, 950),
+        &gs::Point::new(700, 650),
+        &gs::Point::new(900, 950),
+        &gs::Point::new(950, 650),
+    );
+    cubic_bezier.draw(&mut image);
+
+    // Chaikin-smoothed pentagon outline
+    let rounded_pentagon_source = gs::Pentagon::new(&gs::Point::new(700, 300), 90);
+    let rounded_pentagon = gs::Chaikin::new(rounded_pentagon_source.points(), 3, true);
+    rounded_pentagon.draw(&mut image);
+
+    // A stack of overlapping, blended circles: each mode accumulates color
+    // with what's already on the image instead of clobbering it.
+    gs::Circle::new(&gs::Point::new(500, 150), 70).fill_blended(&mut image, gs::FillRule::NonZero, gs::BlendMode::Multiply);
+    gs::Circle::new(&gs::Point::new(560, 150), 70).fill_blended(&mut image, gs::FillRule::NonZero, gs::BlendMode::Screen);
+    gs::Circle::new(&gs::Point::new(530, 200), 70).fill_blended(&mut image, gs::FillRule::NonZero, gs::BlendMode::Difference);
+    gs::Circle::new(&gs::Point::new(500, 230), 70).fill_blended(&mut image, gs::FillRule::NonZero, gs::BlendMode::Darken);
+    gs::Circle::new(&gs::Point::new(560, 230), 70).fill_blended(&mut image, gs::FillRule::NonZero, gs::BlendMode::Lighten);
+    gs::Circle::new(&gs::Point::new(530, 280), 70).fill_blended(&mut image, gs::FillRule::NonZero, gs::BlendMode::Add);
+
+    // A blended outline on top of the stack, so draw_blended (not just fill_blended) gets exercised
+    let blended_pentagon = gs::Pentagon::new(&gs::Point::new(530, 215), 95);
+    blended_pentagon.draw_blended(&mut image, gs::BlendMode::Difference);
+
+    // A pentagon filled with a two-stop linear gradient instead of a flat color
+    let gradient_pentagon = gs::Pentagon::new(&gs::Point::new(450, 550), 100);
+    let linear_gradient = gs::Paint::LinearGradient {
+        start: gs::Point::new(350, 450),
+        end: gs::Point::new(550, 650),
+        stops: vec![
+            (0.0, Color::rgb(255, 120, 0)),
+            (1.0, Color::rgb(0, 90, 255)),
+        ],
+    };
+    gradient_pentagon.fill_with_paint(&mut image, gs::FillRule::NonZero, &linear_gradient);
+
+    // A circle filled with a radial gradient
+    let gradient_circle_center = gs::Point::new(150, 500);
+    let radial_gradient = gs::Paint::RadialGradient {
+        center: gs::Point::new(150, 500),
+        radius: 90.0,
+        stops: vec![
+            (0.0, Color::rgb(255, 255, 255)),
+            (1.0, Color::rgb(40, 0, 80)),
+        ],
+    };
+    gs::Circle::new(&gradient_circle_center, 90).fill_with_paint(&mut image, gs::FillRule::NonZero, &radial_gradient);
+
+    // An outline stroked through the Paint path with a flat Paint::Solid, the
+    // base case the gradient variants build on
+    let solid_paint = gs::Paint::Solid(Color::rgb(0, 200, 120));
+    gs::Triangle::new(
+        &gs::Point::new(850, 550),
+        &gs::Point::new(800, 680),
+        &gs::Point::new(950, 680),
+    )
+    .draw_with_paint(&mut image, &solid_paint);
+
     raster::save(&image, "image.png").unwrap();
 }
 
@@ -53,4 +146,12 @@ impl Displayable for Image {
             self.set_pixel(x, y, color).unwrap();
         }
     }
+
+    fn get(&self, x: i32, y: i32) -> Color {
+        if x >= 0 && x < self.width && y >= 0 && y < self.height {
+            self.get_pixel(x, y).unwrap_or(Color::rgba(0, 0, 0, 0))
+        } else {
+            Color::rgba(0, 0, 0, 0)
+        }
+    }
 }
\ No newline at end of file